@@ -16,6 +16,9 @@ pub enum Intent {
     AskUserId,
     AskBio,
     AskAvatar,
+    JoinVoice,
+    LeaveVoice,
+    PlayUrl,
 }
 
 pub struct IntentMatcher {
@@ -38,6 +41,9 @@ impl IntentMatcher {
                 (vec!["what is my id", "my user id", "what's my id", "my userid"], Intent::AskUserId),
                 (vec!["what is my bio", "my bio", "what's my bio", "my about me"], Intent::AskBio),
                 (vec!["my avatar", "what's my avatar", "my profile picture", "my pfp"], Intent::AskAvatar),
+                (vec!["join voice", "join vc", "join my channel", "join my vc"], Intent::JoinVoice),
+                (vec!["leave voice", "leave vc", "disconnect", "get out of vc"], Intent::LeaveVoice),
+                (vec!["play "], Intent::PlayUrl),
             ],
         }
     }
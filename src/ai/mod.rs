@@ -21,7 +21,7 @@ impl GeminiClient {
         }
     }
 
-    pub async fn generate_response(&self, prompt: &str, user: &User) -> Result<String> {
+    pub async fn generate_response(&self, prompt: &str, user: &User, bot_name: &str) -> Result<String> {
         let url = format!(
             "https://generativelanguage.googleapis.com/v1beta/models/gemini-1.5-flash-latest:generateContent?key={}",
             self.api_key
@@ -35,13 +35,13 @@ impl GeminiClient {
         );
 
         let system_prompt = format!(
-            "You are Axis, a helpful Discord bot specifically designed for a Roblox Development server. \
+            "You are {}, a helpful Discord bot specifically designed for a Roblox Development server. \
             Your primary purpose is to assist with Roblox game development, Luau scripting, and development best practices. \
             You have extensive knowledge about Roblox Studio, Roblox APIs, game design patterns, and optimization techniques. \
             Be friendly, concise (max 2000 characters), and helpful. When providing code examples, use Luau syntax. \
             Current user context: {}. \
             User message: {}",
-            user_context, prompt
+            bot_name, user_context, prompt
         );
 
         let payload = json!({
@@ -80,11 +80,11 @@ impl GeminiClient {
             .unwrap_or("I'm having trouble generating a response right now.")
             .to_string();
 
-        if text.len() > 2000 {
-            Ok(format!("{}...", &text[..1997]))
-        } else {
-            Ok(text)
-        }
+        // Responses can run well past Discord's 2000-character limit (Roblox
+        // code samples especially); the caller is responsible for splitting
+        // this (`util::split_into_chunks` / `util::send_chunked`) rather than
+        // truncating it here.
+        Ok(text)
     }
 
     pub fn should_respond_to_message(
@@ -1,18 +1,24 @@
 use serenity::async_trait;
 use serenity::builder::{CreateInteractionResponse, CreateInteractionResponseMessage, CreateMessage, CreateEmbed};
 use serenity::client::{Context, EventHandler};
+use serenity::model::event::MessageUpdateEvent;
 use serenity::model::gateway::Ready;
-use serenity::model::id::{ChannelId, UserId};
+use serenity::model::id::{ChannelId, GuildId, MessageId, UserId};
 use serenity::model::prelude::*;
 use serenity::prelude::*;
 use std::sync::Arc;
+use std::time::Duration;
 use dashmap::DashMap;
 use tracing::{error, info, debug};
 use chrono::Utc;
 
 use crate::ai::{GeminiClient, intents::{IntentMatcher, Intent}};
 use crate::commands;
+use crate::commands::components::ComponentRegistry;
 use crate::config::Config;
+use crate::ghost_ping::GhostPingCache;
+use crate::guild_settings::GuildSettingsStore;
+use crate::persona::PersonaManager;
 
 pub struct ShardManagerContainer;
 
@@ -25,6 +31,10 @@ pub struct Handler {
     pub gemini_client: GeminiClient,
     pub active_conversations: Arc<DashMap<ChannelId, UserId>>,
     pub intent_matcher: IntentMatcher,
+    pub component_registry: ComponentRegistry,
+    pub guild_settings: GuildSettingsStore,
+    pub persona_manager: PersonaManager,
+    pub ghost_ping_cache: GhostPingCache,
 }
 
 impl Handler {
@@ -36,6 +46,10 @@ impl Handler {
             gemini_client,
             active_conversations: Arc::new(DashMap::new()),
             intent_matcher: IntentMatcher::new(),
+            component_registry: ComponentRegistry::new(),
+            guild_settings: GuildSettingsStore::load(),
+            persona_manager: PersonaManager::new(),
+            ghost_ping_cache: GhostPingCache::new(),
         }
     }
 
@@ -81,8 +95,11 @@ impl Handler {
                             .field("💬 Channels", guild.channels.len().to_string(), true)
                             .field("🆔 Server ID", format!("`{}`", guild.id), false)
                             .timestamp(Utc::now());
-                        
-                        msg.reply(&http, CreateMessage::new().embed(embed)).await?;
+
+                        msg.reply(&http, CreateMessage::new()
+                            .embed(embed)
+                            .components(commands::serverinfo_components(guild.owner_id))
+                        ).await?;
                     }
                 }
             },
@@ -96,8 +113,11 @@ impl Handler {
                             .field("🏠 Server", guild.name, false)
                             .field("📊 Total Members", format!("**{}** members", guild.member_count), false)
                             .timestamp(Utc::now());
-                        
-                        msg.reply(&http, CreateMessage::new().embed(embed)).await?;
+
+                        msg.reply(&http, CreateMessage::new()
+                            .embed(embed)
+                            .components(commands::membercount_components())
+                        ).await?;
                     }
                 }
             },
@@ -126,6 +146,51 @@ impl Handler {
                     .color(0x5865F2);
                 msg.reply(&http, CreateMessage::new().embed(embed)).await?;
             },
+            Intent::JoinVoice => {
+                #[cfg(feature = "songbird")]
+                {
+                    let guild_id = msg.guild_id;
+                    let channel_id = guild_id.and_then(|g| crate::voice::resolve_author_channel(ctx, g, msg.author.id));
+                    match (guild_id, channel_id) {
+                        (Some(guild_id), Some(channel_id)) => match crate::voice::join(ctx, guild_id, channel_id).await {
+                            Ok(()) => { msg.reply(&http, format!("Joined <#{}>! 🔊", channel_id)).await?; }
+                            Err(e) => { error!("Failed to join voice channel: {}", e); msg.reply(&http, "I couldn't join that voice channel.").await?; }
+                        },
+                        _ => { msg.reply(&http, "You need to be in a voice channel first!").await?; }
+                    }
+                }
+                #[cfg(not(feature = "songbird"))]
+                { msg.reply(&http, "Voice support isn't enabled on this build.").await?; }
+            },
+            Intent::LeaveVoice => {
+                #[cfg(feature = "songbird")]
+                {
+                    match msg.guild_id {
+                        Some(guild_id) => match crate::voice::leave(ctx, guild_id).await {
+                            Ok(()) => { msg.reply(&http, "Left the voice channel. 👋").await?; }
+                            Err(e) => { error!("Failed to leave voice channel: {}", e); msg.reply(&http, "I wasn't in a voice channel.").await?; }
+                        },
+                        None => { msg.reply(&http, "This only works in a server.").await?; }
+                    }
+                }
+                #[cfg(not(feature = "songbird"))]
+                { msg.reply(&http, "Voice support isn't enabled on this build.").await?; }
+            },
+            Intent::PlayUrl => {
+                #[cfg(feature = "songbird")]
+                {
+                    let url = find_play_argument(&msg.content);
+                    match (msg.guild_id, url) {
+                        (Some(guild_id), Some(url)) if !url.is_empty() => match crate::voice::play_url(ctx, guild_id, &url).await {
+                            Ok(()) => { msg.reply(&http, format!("Now playing: {}", url)).await?; }
+                            Err(e) => { error!("Failed to play {}: {}", url, e); msg.reply(&http, "I couldn't play that. Make sure I've joined a voice channel first!").await?; }
+                        },
+                        _ => { msg.reply(&http, "Tell me what to play, e.g. `play <url>`.").await?; }
+                    }
+                }
+                #[cfg(not(feature = "songbird"))]
+                { msg.reply(&http, "Voice support isn't enabled on this build.").await?; }
+            },
             _ => Ok(())
         }?;
         Ok(())
@@ -139,11 +204,21 @@ impl EventHandler for Handler {
         info!("Bot ID: {}", ready.user.id);
         info!("Connected to {} guilds", ready.guilds.len());
         
-        let register_commands = vec![
+        let mut register_commands = vec![
             commands::register_ping(),
             commands::register_serverinfo(),
             commands::register_membercount(),
+            commands::moderation::register_ban(),
+            commands::moderation::register_kick(),
+            commands::moderation::register_timeout(),
+            commands::guild_config::register_config(),
         ];
+        #[cfg(feature = "songbird")]
+        register_commands.extend([
+            commands::register_join(),
+            commands::register_leave(),
+            commands::register_play(),
+        ]);
 
         match Command::set_global_commands(&ctx.http, register_commands).await {
             Ok(commands) => info!("Successfully registered {} application commands", commands.len()),
@@ -158,6 +233,16 @@ impl EventHandler for Handler {
                 "ping" => commands::ping(&ctx, &command).await,
                 "serverinfo" => commands::serverinfo(&ctx, &command).await,
                 "membercount" => commands::membercount(&ctx, &command).await,
+                "ban" => commands::moderation::ban(&ctx, &command).await,
+                "kick" => commands::moderation::kick(&ctx, &command).await,
+                "timeout" => commands::moderation::timeout(&ctx, &command).await,
+                "config" => commands::guild_config::config(&ctx, &command, &self.guild_settings, &self.config).await,
+                #[cfg(feature = "songbird")]
+                "join" => commands::join(&ctx, &command).await,
+                #[cfg(feature = "songbird")]
+                "leave" => commands::leave(&ctx, &command).await,
+                #[cfg(feature = "songbird")]
+                "play" => commands::play(&ctx, &command).await,
                 _ => {
                     error!("Unknown command: {}", command.data.name);
                     Ok(())
@@ -173,6 +258,11 @@ impl EventHandler for Handler {
                 );
                 let _ = command.create_response(&ctx.http, response).await;
             }
+        } else if let Interaction::Component(component) = interaction {
+            info!("Received component interaction: {}", component.data.custom_id);
+            if let Err(e) = self.component_registry.dispatch(&ctx, &component).await {
+                error!("Error handling component {}: {}", component.data.custom_id, e);
+            }
         }
     }
 
@@ -184,6 +274,15 @@ impl EventHandler for Handler {
         debug!("Received message from {}: {}", msg.author.tag(), msg.content);
         let http = ctx.http.clone();
 
+        if !msg.mentions.is_empty() {
+            self.ghost_ping_cache.track(
+                msg.id,
+                msg.author.id,
+                msg.content.clone(),
+                msg.mentions.iter().map(|u| u.id).collect(),
+            );
+        }
+
         if self.intent_matcher.should_stop_conversation(&msg.content, msg.author.id, msg.channel_id, &self.active_conversations) {
             info!("Stopping conversation with {} in channel {}", msg.author.tag(), msg.channel_id);
             self.active_conversations.remove(&msg.channel_id);
@@ -195,9 +294,10 @@ impl EventHandler for Handler {
 
         if let Some(intent) = self.intent_matcher.detect_intent(&msg.content) {
             match intent {
-                Intent::CheckPing | Intent::CheckServerInfo | Intent::CheckMemberCount | 
-                Intent::AskUsername | Intent::AskNickname | Intent::AskUserId | 
-                Intent::AskBio | Intent::AskAvatar => {
+                Intent::CheckPing | Intent::CheckServerInfo | Intent::CheckMemberCount |
+                Intent::AskUsername | Intent::AskNickname | Intent::AskUserId |
+                Intent::AskBio | Intent::AskAvatar | Intent::JoinVoice |
+                Intent::LeaveVoice | Intent::PlayUrl => {
                     if let Err(e) = self.handle_command_intent(&ctx, &msg, intent).await {
                         error!("Failed to handle command intent: {}", e);
                     }
@@ -207,9 +307,22 @@ impl EventHandler for Handler {
             }
         }
         
+        let guild_settings = msg.guild_id.map(|g| self.guild_settings.get(g)).unwrap_or_default();
+
+        if msg.guild_id.is_some() {
+            if !guild_settings.ai_enabled.unwrap_or(true) {
+                return;
+            }
+            if !guild_settings.is_channel_allowed(msg.channel_id) {
+                return;
+            }
+        }
+
+        let bot_name = guild_settings.bot_name.as_deref().unwrap_or(&self.config.bot_name);
+
         let should_respond = self.gemini_client.should_respond_to_message(
             &msg.content,
-            &self.config.bot_name,
+            bot_name,
             msg.author.id,
             msg.channel_id,
             &self.active_conversations,
@@ -227,11 +340,33 @@ impl EventHandler for Handler {
 
             let _typing_guard = msg.channel_id.start_typing(&http);
             
-            match self.gemini_client.generate_response(&msg.content, &msg.author).await {
+            match self.gemini_client.generate_response(&msg.content, &msg.author, bot_name).await {
                 Ok(response) => {
                     debug!("Generated AI response: {}", response);
-                    if let Err(e) = msg.reply(&http, response).await {
-                        error!("Failed to send AI response: {}", e);
+                    let chunks = crate::util::split_into_chunks(&response, crate::util::DISCORD_MESSAGE_LIMIT);
+                    let mut first_unsent = 0;
+
+                    if msg.guild_id.is_some() {
+                        let persona_avatar = guild_settings.persona_avatar_url.as_deref();
+                        for chunk in &chunks {
+                            match self.persona_manager.send_as(&http, msg.channel_id, bot_name, persona_avatar, chunk).await {
+                                Ok(()) => first_unsent += 1,
+                                Err(e) => {
+                                    error!("Failed to send persona reply chunk: {}", e);
+                                    break;
+                                }
+                            }
+                        }
+                    }
+
+                    // Anything the persona webhook didn't get to (either because it's
+                    // disabled outside guilds, or a chunk failed partway through) still
+                    // needs to reach the channel, just without the persona identity.
+                    if first_unsent < chunks.len() {
+                        let remainder = chunks[first_unsent..].join("");
+                        if let Err(e) = crate::util::send_chunked(&http, msg.channel_id, &remainder).await {
+                            error!("Failed to send AI response: {}", e);
+                        }
                     }
                 }
                 Err(e) => {
@@ -250,4 +385,62 @@ impl EventHandler for Handler {
             }
         }
     }
+
+    async fn message_update(&self, _ctx: Context, _old_if_available: Option<Message>, _new: Option<Message>, event: MessageUpdateEvent) {
+        if let Some(content) = event.content {
+            self.ghost_ping_cache.update_content(event.id, content);
+        }
+    }
+
+    async fn message_delete(&self, ctx: Context, channel_id: ChannelId, deleted_message_id: MessageId, guild_id: Option<GuildId>) {
+        let Some(guild_id) = guild_id else { return };
+
+        let settings = self.guild_settings.get(guild_id);
+        if !settings.ghost_ping_detection.unwrap_or(false) {
+            return;
+        }
+
+        let window = Duration::from_secs(settings.ghost_ping_window_secs.unwrap_or(crate::ghost_ping::DEFAULT_WINDOW_SECS));
+        let Some(entry) = self.ghost_ping_cache.take_if_within_window(deleted_message_id, window) else { return };
+        if entry.mentions.is_empty() {
+            return;
+        }
+
+        info!("Detected ghost ping by {} in channel {}", entry.author_id, channel_id);
+
+        let pinged = entry.mentions.iter().map(|id| format!("<@{}>", id)).collect::<Vec<_>>().join(", ");
+        let content = if entry.content.is_empty() { "*(no text content)*".to_string() } else { entry.content };
+
+        let embed = CreateEmbed::new()
+            .title("👻 Ghost Ping Detected")
+            .color(0xED4245)
+            .field("Author", format!("<@{}>", entry.author_id), true)
+            .field("Pinged", pinged, true)
+            .field("Original Message", content, false)
+            .timestamp(Utc::now());
+
+        if let Err(e) = channel_id.send_message(&ctx.http, CreateMessage::new().embed(embed)).await {
+            error!("Failed to send ghost ping alert: {}", e);
+        }
+    }
+}
+
+/// Finds a case-insensitive `"play "` marker in `content` and returns whatever
+/// follows it, trimmed. Scans the original string directly rather than
+/// lowercasing it first, since `to_lowercase()` can change a string's byte
+/// length (e.g. `İ`), which would desync a byte index computed against the
+/// lowercased copy from the original `content` it's sliced out of.
+#[cfg(feature = "songbird")]
+fn find_play_argument(content: &str) -> Option<String> {
+    const NEEDLE: &str = "play ";
+    for (start, _) in content.char_indices() {
+        let rest = &content[start..];
+        if rest.len() >= NEEDLE.len()
+            && rest.is_char_boundary(NEEDLE.len())
+            && rest[..NEEDLE.len()].eq_ignore_ascii_case(NEEDLE)
+        {
+            return Some(rest[NEEDLE.len()..].trim().to_string());
+        }
+    }
+    None
 }
@@ -0,0 +1,99 @@
+use serenity::builder::{CreateInteractionResponse, CreateInteractionResponseMessage};
+use serenity::client::Context;
+use serenity::model::application::ComponentInteraction;
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use tracing::{error, warn};
+
+use crate::commands;
+
+type ComponentFuture<'a> = Pin<Box<dyn Future<Output = Result<(), serenity::Error>> + Send + 'a>>;
+type ComponentHandler = for<'a> fn(&'a Context, &'a ComponentInteraction) -> ComponentFuture<'a>;
+
+/// Maps a button/select `custom_id` to the closure that rebuilds its message.
+///
+/// New interactive components register themselves here instead of growing the
+/// `Interaction::Component` match arm in `interaction_create`.
+pub struct ComponentRegistry {
+    handlers: HashMap<&'static str, ComponentHandler>,
+}
+
+impl ComponentRegistry {
+    pub fn new() -> Self {
+        let mut handlers: HashMap<&'static str, ComponentHandler> = HashMap::new();
+        handlers.insert("serverinfo_refresh", |ctx, component| {
+            Box::pin(refresh_serverinfo(ctx, component))
+        });
+        handlers.insert("membercount_refresh", |ctx, component| {
+            Box::pin(refresh_membercount(ctx, component))
+        });
+        Self { handlers }
+    }
+
+    pub async fn dispatch(&self, ctx: &Context, component: &ComponentInteraction) -> Result<(), serenity::Error> {
+        match self.handlers.get(component.data.custom_id.as_str()) {
+            Some(handler) => handler(ctx, component).await,
+            None => {
+                warn!("No component handler registered for custom_id: {}", component.data.custom_id);
+                Ok(())
+            }
+        }
+    }
+}
+
+async fn refresh_serverinfo(ctx: &Context, component: &ComponentInteraction) -> Result<(), serenity::Error> {
+    let http = ctx.http.clone();
+    let guild_id = match component.guild_id {
+        Some(id) => id,
+        None => {
+            error!("serverinfo_refresh pressed outside a guild");
+            return Ok(());
+        }
+    };
+
+    match commands::build_serverinfo_embed(ctx, guild_id).await {
+        Ok((embed, owner_id)) => {
+            let response = CreateInteractionResponse::UpdateMessage(
+                CreateInteractionResponseMessage::new()
+                    .embed(embed)
+                    .components(commands::serverinfo_components(owner_id)),
+            );
+            component.create_response(&http, response).await?;
+        }
+        Err(_) => {
+            let response = CreateInteractionResponse::Acknowledge;
+            component.create_response(&http, response).await?;
+        }
+    }
+
+    Ok(())
+}
+
+async fn refresh_membercount(ctx: &Context, component: &ComponentInteraction) -> Result<(), serenity::Error> {
+    let http = ctx.http.clone();
+    let guild_id = match component.guild_id {
+        Some(id) => id,
+        None => {
+            error!("membercount_refresh pressed outside a guild");
+            return Ok(());
+        }
+    };
+
+    match commands::build_membercount_embed(ctx, guild_id) {
+        Ok(embed) => {
+            let response = CreateInteractionResponse::UpdateMessage(
+                CreateInteractionResponseMessage::new()
+                    .embed(embed)
+                    .components(commands::membercount_components()),
+            );
+            component.create_response(&http, response).await?;
+        }
+        Err(_) => {
+            let response = CreateInteractionResponse::Acknowledge;
+            component.create_response(&http, response).await?;
+        }
+    }
+
+    Ok(())
+}
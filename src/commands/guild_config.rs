@@ -0,0 +1,125 @@
+use serenity::builder::{CreateCommand, CreateCommandOption, CreateInteractionResponse, CreateInteractionResponseMessage};
+use serenity::model::application::{CommandOptionType, ResolvedOption, ResolvedValue};
+use serenity::model::prelude::*;
+use serenity::model::Permissions;
+use serenity::prelude::*;
+
+use crate::config::Config;
+use crate::guild_settings::GuildSettingsStore;
+
+async fn reply_ephemeral(ctx: &Context, command: &CommandInteraction, content: impl Into<String>) -> Result<(), serenity::Error> {
+    command
+        .create_response(
+            &ctx.http,
+            CreateInteractionResponse::Message(
+                CreateInteractionResponseMessage::new().content(content).ephemeral(true),
+            ),
+        )
+        .await
+}
+
+/// Handles both `/config set` and `/config show` subcommands.
+pub async fn config(
+    ctx: &Context,
+    command: &CommandInteraction,
+    store: &GuildSettingsStore,
+    global_config: &Config,
+) -> Result<(), serenity::Error> {
+    let Some(guild_id) = command.guild_id else {
+        return reply_ephemeral(ctx, command, "This command can only be used in a server.").await;
+    };
+
+    let options = command.data.options();
+    let Some(subcommand) = options.first() else {
+        return reply_ephemeral(ctx, command, "You must specify a subcommand.").await;
+    };
+
+    match (subcommand.name, &subcommand.value) {
+        ("show", _) => {
+            let settings = store.get(guild_id);
+            let content = format!(
+                "**Server configuration**\n\
+                Bot name: `{}`\n\
+                AI enabled: `{}`\n\
+                Persona avatar: `{}`\n\
+                Ghost-ping detection: `{}` (window: {}s)\n\
+                Allowed channels: {}",
+                settings.bot_name.as_deref().unwrap_or(&global_config.bot_name),
+                settings.ai_enabled.unwrap_or(true),
+                settings.persona_avatar_url.as_deref().unwrap_or("default"),
+                settings.ghost_ping_detection.unwrap_or(false),
+                settings.ghost_ping_window_secs.unwrap_or(crate::ghost_ping::DEFAULT_WINDOW_SECS),
+                if settings.allowed_channels.is_empty() {
+                    "all channels".to_string()
+                } else {
+                    settings.allowed_channels.iter().map(|c| format!("<#{}>", c)).collect::<Vec<_>>().join(", ")
+                }
+            );
+            reply_ephemeral(ctx, command, content).await
+        }
+        ("set", ResolvedValue::SubCommand(set_options)) => {
+            let mut updated = Vec::new();
+
+            for opt in set_options {
+                match (opt.name, &opt.value) {
+                    ("bot_name", ResolvedValue::String(name)) => {
+                        store.update(guild_id, |settings| settings.bot_name = Some(name.to_string()));
+                        updated.push(format!("bot name → `{}`", name));
+                    }
+                    ("ai_enabled", ResolvedValue::Boolean(enabled)) => {
+                        store.update(guild_id, |settings| settings.ai_enabled = Some(*enabled));
+                        updated.push(format!("AI enabled → `{}`", enabled));
+                    }
+                    ("persona_avatar_url", ResolvedValue::String(url)) => {
+                        store.update(guild_id, |settings| settings.persona_avatar_url = Some(url.to_string()));
+                        updated.push("persona avatar → updated".to_string());
+                    }
+                    ("ghost_ping_detection", ResolvedValue::Boolean(enabled)) => {
+                        store.update(guild_id, |settings| settings.ghost_ping_detection = Some(*enabled));
+                        updated.push(format!("ghost-ping detection → `{}`", enabled));
+                    }
+                    ("ghost_ping_window_secs", ResolvedValue::Integer(secs)) => {
+                        let secs = (*secs).max(0) as u64;
+                        store.update(guild_id, |settings| settings.ghost_ping_window_secs = Some(secs));
+                        updated.push(format!("ghost-ping window → `{}s`", secs));
+                    }
+                    _ => {}
+                }
+            }
+
+            if updated.is_empty() {
+                reply_ephemeral(ctx, command, "Provide at least one setting to change.").await
+            } else {
+                reply_ephemeral(ctx, command, format!("Updated: {}", updated.join(", "))).await
+            }
+        }
+        _ => reply_ephemeral(ctx, command, "Unknown subcommand.").await,
+    }
+}
+
+pub fn register_config() -> CreateCommand {
+    CreateCommand::new("config")
+        .description("View or change this server's bot configuration")
+        .default_member_permissions(Permissions::ADMINISTRATOR)
+        .add_option(
+            CreateCommandOption::new(CommandOptionType::SubCommand, "show", "Show the current configuration"),
+        )
+        .add_option(
+            CreateCommandOption::new(CommandOptionType::SubCommand, "set", "Change one or more settings")
+                .add_sub_option(
+                    CreateCommandOption::new(CommandOptionType::String, "bot_name", "The name the bot responds to").required(false),
+                )
+                .add_sub_option(
+                    CreateCommandOption::new(CommandOptionType::Boolean, "ai_enabled", "Whether the AI chat is enabled").required(false),
+                )
+                .add_sub_option(
+                    CreateCommandOption::new(CommandOptionType::String, "persona_avatar_url", "Avatar URL for the AI's webhook persona").required(false),
+                )
+                .add_sub_option(
+                    CreateCommandOption::new(CommandOptionType::Boolean, "ghost_ping_detection", "Whether to report deleted mention messages").required(false),
+                )
+                .add_sub_option(
+                    CreateCommandOption::new(CommandOptionType::Integer, "ghost_ping_window_secs", "Seconds after sending a mention still counts as a ghost ping").required(false),
+                ),
+        )
+}
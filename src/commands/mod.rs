@@ -1,8 +1,29 @@
-use serenity::builder::{CreateCommand, CreateEmbed, CreateInteractionResponse, CreateInteractionResponseMessage, EditInteractionResponse};
+pub mod components;
+pub mod guild_config;
+pub mod moderation;
+
+use serenity::builder::{CreateActionRow, CreateButton, CreateCommand, CreateEmbed, CreateInteractionResponse, CreateInteractionResponseMessage, EditInteractionResponse};
 use serenity::model::prelude::*;
 use serenity::prelude::*;
 use chrono::{DateTime, Utc};
 
+/// Action row shown under the `serverinfo` embed: a refresh button that
+/// re-runs the cache lookup, plus a link button straight to the owner.
+pub fn serverinfo_components(owner_id: UserId) -> Vec<CreateActionRow> {
+    vec![CreateActionRow::Buttons(vec![
+        CreateButton::new("serverinfo_refresh").label("🔄 Refresh"),
+        CreateButton::new_link(format!("https://discord.com/users/{}", owner_id)).label("Jump to Owner"),
+    ])]
+}
+
+/// Action row shown under the `membercount` embed: a refresh button that
+/// re-runs the cache lookup.
+pub fn membercount_components() -> Vec<CreateActionRow> {
+    vec![CreateActionRow::Buttons(vec![
+        CreateButton::new("membercount_refresh").label("🔄 Refresh"),
+    ])]
+}
+
 pub async fn ping(ctx: &Context, command: &CommandInteraction) -> Result<(), serenity::Error> {
     let http = ctx.http.clone(); // Clone http client
     let start = std::time::Instant::now();
@@ -27,21 +48,10 @@ pub async fn ping(ctx: &Context, command: &CommandInteraction) -> Result<(), ser
     Ok(())
 }
 
-pub async fn serverinfo(ctx: &Context, command: &CommandInteraction) -> Result<(), serenity::Error> {
-    let http = ctx.http.clone(); // Clone http client
-    let guild_id = match command.guild_id {
-        Some(id) => id,
-        None => {
-            let response = CreateInteractionResponse::Message(
-                CreateInteractionResponseMessage::new()
-                    .content("This command can only be used in a server.")
-                    .ephemeral(true)
-            );
-            command.create_response(&http, response).await?;
-            return Ok(());
-        }
-    };
-
+/// Builds the `serverinfo` embed from the cache, returning the owner's ID
+/// alongside it so the caller can attach the "Jump to Owner" link button.
+/// Shared between the slash command and the `serverinfo_refresh` component.
+pub async fn build_serverinfo_embed(ctx: &Context, guild_id: GuildId) -> Result<(CreateEmbed, UserId), ()> {
     // Perform cache access and data processing in a separate block, returning a Result.
     type ServerInfoData = (String, String, String, UserId, String, String, String, String, String, String, String);
     let guild_info_result: Result<ServerInfoData, ()> = {
@@ -69,40 +79,62 @@ pub async fn serverinfo(ctx: &Context, command: &CommandInteraction) -> Result<(
         }
     }; // CacheRef is dropped here.
 
+    let (
+        guild_name,
+        icon_url,
+        server_id_str,
+        owner_id,
+        member_count_str,
+        created_at_str,
+        roles_len_str,
+        channels_len_str,
+        premium_tier_str,
+        boosters_str,
+        verification_level_str,
+    ) = guild_info_result?;
+
+    // All data is owned and Send. Perform awaits using this data.
+    let owner_tag = owner_id.to_user(&ctx.http).await.map_or("Unknown".to_string(), |u| u.tag());
+
+    let embed = CreateEmbed::new()
+        .title(format!("{} Server Information", guild_name))
+        .color(0x00ff00)
+        .thumbnail(icon_url)
+        .field("Server ID", server_id_str, true)
+        .field("Owner", owner_tag, true)
+        .field("Member Count", member_count_str, true)
+        .field("Creation Date", created_at_str, true)
+        .field("Roles", roles_len_str, true)
+        .field("Channels", channels_len_str, true)
+        .field("Boost Level", premium_tier_str, true)
+        .field("Boosters", boosters_str, true)
+        .field("Verification Level", verification_level_str, true);
+
+    Ok((embed, owner_id))
+}
+
+pub async fn serverinfo(ctx: &Context, command: &CommandInteraction) -> Result<(), serenity::Error> {
+    let http = ctx.http.clone(); // Clone http client
+    let guild_id = match command.guild_id {
+        Some(id) => id,
+        None => {
+            let response = CreateInteractionResponse::Message(
+                CreateInteractionResponseMessage::new()
+                    .content("This command can only be used in a server.")
+                    .ephemeral(true)
+            );
+            command.create_response(&http, response).await?;
+            return Ok(());
+        }
+    };
+
     // Handle the result of cache access.
-    match guild_info_result {
-        Ok((
-            guild_name,
-            icon_url,
-            server_id_str,
-            owner_id,
-            member_count_str,
-            created_at_str,
-            roles_len_str,
-            channels_len_str,
-            premium_tier_str,
-            boosters_str,
-            verification_level_str,
-        )) => {
-            // All data is owned and Send. Perform awaits using this data.
-            let owner_tag = owner_id.to_user(&http).await.map_or("Unknown".to_string(), |u| u.tag());
-            
-            let embed = CreateEmbed::new() // This was the start of the misplaced block
-                .title(format!("{} Server Information", guild_name))
-                .color(0x00ff00)
-                .thumbnail(icon_url)
-                .field("Server ID", server_id_str, true)
-                .field("Owner", owner_tag, true)
-                .field("Member Count", member_count_str, true)
-                .field("Creation Date", created_at_str, true)
-                .field("Roles", roles_len_str, true)
-                .field("Channels", channels_len_str, true)
-                .field("Boost Level", premium_tier_str, true)
-                .field("Boosters", boosters_str, true)
-                .field("Verification Level", verification_level_str, true);
-            
+    match build_serverinfo_embed(ctx, guild_id).await {
+        Ok((embed, owner_id)) => {
             let response = CreateInteractionResponse::Message(
-                CreateInteractionResponseMessage::new().embed(embed)
+                CreateInteractionResponseMessage::new()
+                    .embed(embed)
+                    .components(serverinfo_components(owner_id))
             );
             command.create_response(&http, response).await?;
         }
@@ -120,6 +152,30 @@ pub async fn serverinfo(ctx: &Context, command: &CommandInteraction) -> Result<(
     Ok(())
 }
 
+/// Builds the `membercount` embed from the cache. Shared between the slash
+/// command and the `membercount_refresh` component.
+pub fn build_membercount_embed(ctx: &Context, guild_id: GuildId) -> Result<CreateEmbed, ()> {
+    // Perform cache access and data processing in a separate block, returning a Result.
+    let guild_data_result: Result<(String, u64), ()> = { // Renamed and type changed
+        let guild_option = ctx.cache.guild(guild_id);
+        match guild_option {
+            Some(guild_ref) => {
+                let owned_guild = (*guild_ref).clone();
+                Ok((owned_guild.name.clone(), owned_guild.member_count)) // Return tuple
+            }
+            None => Err(()),
+        }
+    }; // CacheRef (guild_ref) is dropped here.
+
+    let (guild_name, member_count) = guild_data_result?;
+
+    Ok(CreateEmbed::new()
+        .title("Member Statistics")
+        .color(0x00bfff) // Deep sky blue
+        .field("Server", guild_name, true)
+        .field("Members", member_count.to_string(), true))
+}
+
 pub async fn membercount(ctx: &Context, command: &CommandInteraction) -> Result<(), serenity::Error> {
     let http = ctx.http.clone(); // Clone http client
     let guild_id = match command.guild_id {
@@ -135,29 +191,13 @@ pub async fn membercount(ctx: &Context, command: &CommandInteraction) -> Result<
         }
     };
 
-    // Perform cache access and data processing in a separate block, returning a Result.
-    let guild_data_result: Result<(String, u64), ()> = { // Renamed and type changed
-        let guild_option = ctx.cache.guild(guild_id);
-        match guild_option {
-            Some(guild_ref) => {
-                let owned_guild = (*guild_ref).clone();
-                Ok((owned_guild.name.clone(), owned_guild.member_count)) // Return tuple
-            }
-            None => Err(()),
-        }
-    }; // CacheRef (guild_ref) is dropped here.
-
     // Handle the result of cache access.
-    match guild_data_result {
-        Ok((guild_name, member_count)) => {
-            let embed = CreateEmbed::new()
-                .title("Member Statistics")
-                .color(0x00bfff) // Deep sky blue
-                .field("Server", guild_name, true)
-                .field("Members", member_count.to_string(), true);
-
+    match build_membercount_embed(ctx, guild_id) {
+        Ok(embed) => {
             let response = CreateInteractionResponse::Message(
-                CreateInteractionResponseMessage::new().embed(embed)
+                CreateInteractionResponseMessage::new()
+                    .embed(embed)
+                    .components(membercount_components())
             );
             command.create_response(&http, response).await?;
         }
@@ -175,6 +215,97 @@ pub async fn membercount(ctx: &Context, command: &CommandInteraction) -> Result<
     Ok(())
 }
 
+#[cfg(feature = "songbird")]
+pub async fn join(ctx: &Context, command: &CommandInteraction) -> Result<(), serenity::Error> {
+    let http = ctx.http.clone();
+    let guild_id = match command.guild_id {
+        Some(id) => id,
+        None => {
+            command.create_response(&http, CreateInteractionResponse::Message(
+                CreateInteractionResponseMessage::new().content("This command can only be used in a server.").ephemeral(true)
+            )).await?;
+            return Ok(());
+        }
+    };
+
+    let channel_id = crate::voice::resolve_author_channel(ctx, guild_id, command.user.id);
+    let content = match channel_id {
+        Some(channel_id) => match crate::voice::join(ctx, guild_id, channel_id).await {
+            Ok(()) => format!("Joined <#{}>! 🔊", channel_id),
+            Err(_) => "I couldn't join that voice channel.".to_string(),
+        },
+        None => "You need to be in a voice channel first!".to_string(),
+    };
+
+    command.create_response(&http, CreateInteractionResponse::Message(
+        CreateInteractionResponseMessage::new().content(content)
+    )).await?;
+
+    Ok(())
+}
+
+#[cfg(feature = "songbird")]
+pub async fn leave(ctx: &Context, command: &CommandInteraction) -> Result<(), serenity::Error> {
+    let http = ctx.http.clone();
+    let guild_id = match command.guild_id {
+        Some(id) => id,
+        None => {
+            command.create_response(&http, CreateInteractionResponse::Message(
+                CreateInteractionResponseMessage::new().content("This command can only be used in a server.").ephemeral(true)
+            )).await?;
+            return Ok(());
+        }
+    };
+
+    let content = match crate::voice::leave(ctx, guild_id).await {
+        Ok(()) => "Left the voice channel. 👋".to_string(),
+        Err(_) => "I wasn't in a voice channel.".to_string(),
+    };
+
+    command.create_response(&http, CreateInteractionResponse::Message(
+        CreateInteractionResponseMessage::new().content(content)
+    )).await?;
+
+    Ok(())
+}
+
+#[cfg(feature = "songbird")]
+pub async fn play(ctx: &Context, command: &CommandInteraction) -> Result<(), serenity::Error> {
+    use serenity::model::application::{ResolvedOption, ResolvedValue};
+
+    let http = ctx.http.clone();
+    let guild_id = match command.guild_id {
+        Some(id) => id,
+        None => {
+            command.create_response(&http, CreateInteractionResponse::Message(
+                CreateInteractionResponseMessage::new().content("This command can only be used in a server.").ephemeral(true)
+            )).await?;
+            return Ok(());
+        }
+    };
+
+    let url = match command.data.options().first() {
+        Some(ResolvedOption { value: ResolvedValue::String(url), .. }) => url.to_string(),
+        _ => {
+            command.create_response(&http, CreateInteractionResponse::Message(
+                CreateInteractionResponseMessage::new().content("Please provide a URL to play.").ephemeral(true)
+            )).await?;
+            return Ok(());
+        }
+    };
+
+    let content = match crate::voice::play_url(ctx, guild_id, &url).await {
+        Ok(()) => format!("Now playing: {}", url),
+        Err(_) => "I couldn't play that. Make sure I've joined a voice channel first!".to_string(),
+    };
+
+    command.create_response(&http, CreateInteractionResponse::Message(
+        CreateInteractionResponseMessage::new().content(content)
+    )).await?;
+
+    Ok(())
+}
+
 pub fn register_ping() -> CreateCommand {
     CreateCommand::new("ping").description("Check the bot's latency")
 }
@@ -185,4 +316,27 @@ pub fn register_serverinfo() -> CreateCommand {
 
 pub fn register_membercount() -> CreateCommand {
     CreateCommand::new("membercount").description("Display the current member count of the server")
+}
+
+#[cfg(feature = "songbird")]
+pub fn register_join() -> CreateCommand {
+    CreateCommand::new("join").description("Join your current voice channel")
+}
+
+#[cfg(feature = "songbird")]
+pub fn register_leave() -> CreateCommand {
+    CreateCommand::new("leave").description("Leave the voice channel")
+}
+
+#[cfg(feature = "songbird")]
+pub fn register_play() -> CreateCommand {
+    use serenity::builder::CreateCommandOption;
+    use serenity::model::application::CommandOptionType;
+
+    CreateCommand::new("play")
+        .description("Play audio from a URL in your current voice channel")
+        .add_option(
+            CreateCommandOption::new(CommandOptionType::String, "url", "The URL to play")
+                .required(true),
+        )
 }
\ No newline at end of file
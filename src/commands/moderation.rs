@@ -0,0 +1,240 @@
+use serenity::builder::{CreateCommand, CreateCommandOption, CreateInteractionResponse, CreateInteractionResponseMessage};
+use serenity::model::application::{CommandOptionType, ResolvedOption, ResolvedValue};
+use serenity::model::prelude::*;
+use serenity::model::Permissions;
+use serenity::prelude::*;
+use chrono::{Duration, Utc};
+use tracing::{info, warn};
+
+/// Highest role position held by `member`, or `0` (the position of
+/// `@everyone`) if the guild isn't cached or they hold no roles.
+fn highest_role_position(ctx: &Context, guild_id: GuildId, member: &Member) -> i16 {
+    ctx.cache
+        .guild(guild_id)
+        .map(|guild| {
+            member
+                .roles
+                .iter()
+                .filter_map(|role_id| guild.roles.get(role_id))
+                .map(|role| role.position)
+                .max()
+                .unwrap_or(0)
+        })
+        .unwrap_or(0)
+}
+
+/// The guild owner and the bot's own owner can always moderate; everyone
+/// else needs a strictly higher role position than the target.
+async fn can_moderate(ctx: &Context, guild_id: GuildId, invoker: &Member, target: &Member) -> bool {
+    if ctx.cache.guild(guild_id).map_or(false, |guild| guild.owner_id == invoker.user.id) {
+        return true;
+    }
+
+    if let Ok(app_info) = ctx.http.get_current_application_info().await {
+        if app_info.owner.id == invoker.user.id {
+            return true;
+        }
+    }
+
+    highest_role_position(ctx, guild_id, invoker) > highest_role_position(ctx, guild_id, target)
+}
+
+fn string_option<'a>(options: &'a [ResolvedOption<'a>], name: &str) -> Option<&'a str> {
+    options.iter().find_map(|opt| match (&opt.value, opt.name == name) {
+        (ResolvedValue::String(s), true) => Some(*s),
+        _ => None,
+    })
+}
+
+fn integer_option(options: &[ResolvedOption], name: &str) -> Option<i64> {
+    options.iter().find_map(|opt| match (&opt.value, opt.name == name) {
+        (ResolvedValue::Integer(i), true) => Some(*i),
+        _ => None,
+    })
+}
+
+fn user_option<'a>(options: &'a [ResolvedOption<'a>], name: &str) -> Option<&'a User> {
+    options.iter().find_map(|opt| match (&opt.value, opt.name == name) {
+        (ResolvedValue::User(user, _), true) => Some(*user),
+        _ => None,
+    })
+}
+
+async fn reply_ephemeral(ctx: &Context, command: &CommandInteraction, content: impl Into<String>) -> Result<(), serenity::Error> {
+    command
+        .create_response(
+            &ctx.http,
+            CreateInteractionResponse::Message(
+                CreateInteractionResponseMessage::new().content(content).ephemeral(true),
+            ),
+        )
+        .await
+}
+
+pub async fn ban(ctx: &Context, command: &CommandInteraction) -> Result<(), serenity::Error> {
+    let Some(guild_id) = command.guild_id else {
+        return reply_ephemeral(ctx, command, "This command can only be used in a server.").await;
+    };
+    let Some(invoker) = &command.member else {
+        return reply_ephemeral(ctx, command, "Could not resolve your member information.").await;
+    };
+
+    if !invoker.permissions(&ctx.cache).map_or(false, |p| p.ban_members()) {
+        return reply_ephemeral(ctx, command, "You need the **Ban Members** permission to use this.").await;
+    }
+
+    let options = command.data.options();
+    let Some(target_user) = user_option(&options, "user") else {
+        return reply_ephemeral(ctx, command, "You must specify a member to ban.").await;
+    };
+    let reason = string_option(&options, "reason").unwrap_or("No reason provided").to_string();
+
+    let target_member = match guild_id.member(&ctx.http, target_user.id).await {
+        Ok(member) => member,
+        Err(_) => return reply_ephemeral(ctx, command, "Could not find that member in this server.").await,
+    };
+
+    if !can_moderate(ctx, guild_id, invoker, &target_member).await {
+        return reply_ephemeral(ctx, command, "You cannot moderate someone with an equal or higher role than you.").await;
+    }
+
+    match guild_id.ban_with_reason(&ctx.http, target_user.id, 0, &reason).await {
+        Ok(()) => {
+            info!("{} banned {} in guild {} (reason: {})", invoker.user.tag(), target_user.tag(), guild_id, reason);
+            command
+                .create_response(
+                    &ctx.http,
+                    CreateInteractionResponse::Message(
+                        CreateInteractionResponseMessage::new()
+                            .content(format!("🔨 Banned **{}** — {}", target_user.tag(), reason)),
+                    ),
+                )
+                .await
+        }
+        Err(e) => {
+            warn!("Failed to ban {}: {}", target_user.tag(), e);
+            reply_ephemeral(ctx, command, "I couldn't ban that member. Do I have the Ban Members permission?").await
+        }
+    }
+}
+
+pub async fn kick(ctx: &Context, command: &CommandInteraction) -> Result<(), serenity::Error> {
+    let Some(guild_id) = command.guild_id else {
+        return reply_ephemeral(ctx, command, "This command can only be used in a server.").await;
+    };
+    let Some(invoker) = &command.member else {
+        return reply_ephemeral(ctx, command, "Could not resolve your member information.").await;
+    };
+
+    if !invoker.permissions(&ctx.cache).map_or(false, |p| p.kick_members()) {
+        return reply_ephemeral(ctx, command, "You need the **Kick Members** permission to use this.").await;
+    }
+
+    let options = command.data.options();
+    let Some(target_user) = user_option(&options, "user") else {
+        return reply_ephemeral(ctx, command, "You must specify a member to kick.").await;
+    };
+    let reason = string_option(&options, "reason").unwrap_or("No reason provided").to_string();
+
+    let target_member = match guild_id.member(&ctx.http, target_user.id).await {
+        Ok(member) => member,
+        Err(_) => return reply_ephemeral(ctx, command, "Could not find that member in this server.").await,
+    };
+
+    if !can_moderate(ctx, guild_id, invoker, &target_member).await {
+        return reply_ephemeral(ctx, command, "You cannot moderate someone with an equal or higher role than you.").await;
+    }
+
+    match guild_id.kick_with_reason(&ctx.http, target_user.id, &reason).await {
+        Ok(()) => {
+            info!("{} kicked {} in guild {} (reason: {})", invoker.user.tag(), target_user.tag(), guild_id, reason);
+            command
+                .create_response(
+                    &ctx.http,
+                    CreateInteractionResponse::Message(
+                        CreateInteractionResponseMessage::new()
+                            .content(format!("👢 Kicked **{}** — {}", target_user.tag(), reason)),
+                    ),
+                )
+                .await
+        }
+        Err(e) => {
+            warn!("Failed to kick {}: {}", target_user.tag(), e);
+            reply_ephemeral(ctx, command, "I couldn't kick that member. Do I have the Kick Members permission?").await
+        }
+    }
+}
+
+pub async fn timeout(ctx: &Context, command: &CommandInteraction) -> Result<(), serenity::Error> {
+    let Some(guild_id) = command.guild_id else {
+        return reply_ephemeral(ctx, command, "This command can only be used in a server.").await;
+    };
+    let Some(invoker) = &command.member else {
+        return reply_ephemeral(ctx, command, "Could not resolve your member information.").await;
+    };
+
+    if !invoker.permissions(&ctx.cache).map_or(false, |p| p.moderate_members()) {
+        return reply_ephemeral(ctx, command, "You need the **Moderate Members** permission to use this.").await;
+    }
+
+    let options = command.data.options();
+    let Some(target_user) = user_option(&options, "user") else {
+        return reply_ephemeral(ctx, command, "You must specify a member to timeout.").await;
+    };
+    let reason = string_option(&options, "reason").unwrap_or("No reason provided").to_string();
+    let minutes = integer_option(&options, "minutes").unwrap_or(10).clamp(1, 40320); // Discord's 28-day cap
+
+    let mut target_member = match guild_id.member(&ctx.http, target_user.id).await {
+        Ok(member) => member,
+        Err(_) => return reply_ephemeral(ctx, command, "Could not find that member in this server.").await,
+    };
+
+    if !can_moderate(ctx, guild_id, invoker, &target_member).await {
+        return reply_ephemeral(ctx, command, "You cannot moderate someone with an equal or higher role than you.").await;
+    }
+
+    let until = Utc::now() + Duration::minutes(minutes);
+    match target_member.disable_communication_until_datetime(&ctx.http, until.into()).await {
+        Ok(()) => {
+            info!("{} timed out {} for {}m in guild {} (reason: {})", invoker.user.tag(), target_user.tag(), minutes, guild_id, reason);
+            command
+                .create_response(
+                    &ctx.http,
+                    CreateInteractionResponse::Message(
+                        CreateInteractionResponseMessage::new()
+                            .content(format!("🔇 Timed out **{}** for {} minute(s) — {}", target_user.tag(), minutes, reason)),
+                    ),
+                )
+                .await
+        }
+        Err(e) => {
+            warn!("Failed to timeout {}: {}", target_user.tag(), e);
+            reply_ephemeral(ctx, command, "I couldn't time out that member. Do I have the Moderate Members permission?").await
+        }
+    }
+}
+
+pub fn register_ban() -> CreateCommand {
+    CreateCommand::new("ban")
+        .description("Ban a member from the server")
+        .default_member_permissions(Permissions::BAN_MEMBERS)
+        .add_option(CreateCommandOption::new(CommandOptionType::User, "user", "The member to ban").required(true))
+        .add_option(CreateCommandOption::new(CommandOptionType::String, "reason", "Reason for the ban").required(false))
+}
+
+pub fn register_kick() -> CreateCommand {
+    CreateCommand::new("kick")
+        .description("Kick a member from the server")
+        .default_member_permissions(Permissions::KICK_MEMBERS)
+        .add_option(CreateCommandOption::new(CommandOptionType::User, "user", "The member to kick").required(true))
+        .add_option(CreateCommandOption::new(CommandOptionType::String, "reason", "Reason for the kick").required(false))
+}
+
+pub fn register_timeout() -> CreateCommand {
+    CreateCommand::new("timeout")
+        .description("Temporarily mute a member")
+        .default_member_permissions(Permissions::MODERATE_MEMBERS)
+        .add_option(CreateCommandOption::new(CommandOptionType::User, "user", "The member to timeout").required(true))
+        .add_option(CreateCommandOption::new(CommandOptionType::Integer, "minutes", "Duration in minutes (default 10)").required(false))
+        .add_option(CreateCommandOption::new(CommandOptionType::String, "reason", "Reason for the timeout").required(false))
+}
@@ -0,0 +1,51 @@
+//! Tracks recently-sent messages that mention users so a quick deletion
+//! (a "ghost ping") can be reported back to the channel.
+use dashmap::DashMap;
+use serenity::model::id::{MessageId, UserId};
+use std::time::{Duration, Instant};
+
+/// Default window servers get if they opt in without overriding it.
+pub const DEFAULT_WINDOW_SECS: u64 = 60;
+
+/// How long entries are kept around at most, regardless of the per-guild
+/// window, so the cache doesn't grow unbounded in a busy server.
+const MAX_ENTRY_AGE: Duration = Duration::from_secs(60 * 60);
+
+pub struct MentionEntry {
+    pub author_id: UserId,
+    pub content: String,
+    pub mentions: Vec<UserId>,
+    pub sent_at: Instant,
+}
+
+pub struct GhostPingCache {
+    entries: DashMap<MessageId, MentionEntry>,
+}
+
+impl GhostPingCache {
+    pub fn new() -> Self {
+        Self { entries: DashMap::new() }
+    }
+
+    /// Records a message that mentioned users, so it can be checked against
+    /// on deletion. Also sweeps out stale entries.
+    pub fn track(&self, message_id: MessageId, author_id: UserId, content: String, mentions: Vec<UserId>) {
+        self.entries.insert(message_id, MentionEntry { author_id, content, mentions, sent_at: Instant::now() });
+        self.entries.retain(|_, entry| entry.sent_at.elapsed() <= MAX_ENTRY_AGE);
+    }
+
+    /// Keeps a tracked message's cached content in sync with edits, so a
+    /// ghost-ping report reflects what was actually visible right before deletion.
+    pub fn update_content(&self, message_id: MessageId, content: String) {
+        if let Some(mut entry) = self.entries.get_mut(&message_id) {
+            entry.content = content;
+        }
+    }
+
+    /// Removes and returns the tracked entry for `message_id` if it was
+    /// deleted within `window` of being sent.
+    pub fn take_if_within_window(&self, message_id: MessageId, window: Duration) -> Option<MentionEntry> {
+        let (_, entry) = self.entries.remove(&message_id)?;
+        (entry.sent_at.elapsed() <= window).then_some(entry)
+    }
+}
@@ -0,0 +1,88 @@
+//! Per-guild overrides for the bot's global [`Config`](crate::config::Config),
+//! persisted to a small JSON file so they survive restarts.
+use dashmap::DashMap;
+use serde::{Deserialize, Serialize};
+use serenity::model::id::{ChannelId, GuildId};
+use std::collections::HashMap;
+use std::fs;
+use tracing::{error, info, warn};
+
+const SETTINGS_PATH: &str = "guild_settings.json";
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GuildSettings {
+    /// Overrides [`Config::bot_name`](crate::config::Config::bot_name) for this guild.
+    pub bot_name: Option<String>,
+    /// Whether the Gemini-backed AI chat is enabled in this guild at all.
+    pub ai_enabled: Option<bool>,
+    /// Channels the AI is allowed to respond in. Empty means "all channels".
+    pub allowed_channels: Vec<ChannelId>,
+    /// Avatar shown on the webhook persona used for AI replies in this guild.
+    pub persona_avatar_url: Option<String>,
+    /// Whether ghost-ping detection is enabled. Off by default — servers opt in.
+    pub ghost_ping_detection: Option<bool>,
+    /// How soon after sending a mention must be deleted to count as a ghost ping.
+    pub ghost_ping_window_secs: Option<u64>,
+}
+
+impl GuildSettings {
+    pub fn is_channel_allowed(&self, channel_id: ChannelId) -> bool {
+        self.allowed_channels.is_empty() || self.allowed_channels.contains(&channel_id)
+    }
+}
+
+/// Guild-keyed store of [`GuildSettings`], loaded from and flushed back to disk.
+pub struct GuildSettingsStore {
+    settings: DashMap<GuildId, GuildSettings>,
+}
+
+impl GuildSettingsStore {
+    pub fn load() -> Self {
+        let loaded: HashMap<GuildId, GuildSettings> = fs::read_to_string(SETTINGS_PATH)
+            .ok()
+            .and_then(|contents| match serde_json::from_str(&contents) {
+                Ok(map) => Some(map),
+                Err(e) => {
+                    warn!("Failed to parse {}: {}. Starting with empty guild settings.", SETTINGS_PATH, e);
+                    None
+                }
+            })
+            .unwrap_or_default();
+
+        info!("Loaded settings overrides for {} guild(s)", loaded.len());
+        Self {
+            settings: loaded.into_iter().collect(),
+        }
+    }
+
+    /// Returns this guild's settings, or the defaults if it has no overrides.
+    pub fn get(&self, guild_id: GuildId) -> GuildSettings {
+        self.settings.get(&guild_id).map(|s| s.clone()).unwrap_or_default()
+    }
+
+    /// Mutates this guild's settings in place and persists the whole store to disk.
+    pub fn update(&self, guild_id: GuildId, f: impl FnOnce(&mut GuildSettings)) {
+        {
+            let mut entry = self.settings.entry(guild_id).or_default();
+            f(&mut entry);
+        }
+        self.persist();
+    }
+
+    fn persist(&self) {
+        let snapshot: HashMap<GuildId, GuildSettings> = self
+            .settings
+            .iter()
+            .map(|entry| (*entry.key(), entry.value().clone()))
+            .collect();
+
+        match serde_json::to_string_pretty(&snapshot) {
+            Ok(json) => {
+                if let Err(e) = fs::write(SETTINGS_PATH, json) {
+                    error!("Failed to persist {}: {}", SETTINGS_PATH, e);
+                }
+            }
+            Err(e) => error!("Failed to serialize guild settings: {}", e),
+        }
+    }
+}
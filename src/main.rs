@@ -2,6 +2,12 @@ mod ai;
 mod bot;
 mod commands;
 mod config;
+mod ghost_ping;
+mod guild_settings;
+mod persona;
+mod util;
+#[cfg(feature = "songbird")]
+mod voice;
 
 use anyhow::Result;
 use bot::{Handler, ShardManagerContainer};
@@ -34,18 +40,25 @@ async fn main() -> Result<()> {
     };
 
     let handler = Handler::new(config.clone());
-    
+
     let intents = GatewayIntents::GUILD_MESSAGES
         | GatewayIntents::DIRECT_MESSAGES
         | GatewayIntents::MESSAGE_CONTENT
         | GatewayIntents::GUILDS;
+    #[cfg(feature = "songbird")]
+    let intents = intents | GatewayIntents::GUILD_VOICE_STATES;
 
     info!("Creating Discord client with intents: {:?}", intents);
 
-    let mut client = match Client::builder(&config.discord_token, intents)
-        .event_handler(handler)
-        .await
-    {
+    #[cfg(feature = "songbird")]
+    let songbird_manager = songbird::Songbird::serenity();
+
+    let client_builder = Client::builder(&config.discord_token, intents)
+        .event_handler(handler);
+    #[cfg(feature = "songbird")]
+    let client_builder = client_builder.register_songbird_with(songbird_manager.clone());
+
+    let mut client = match client_builder.await {
         Ok(client) => {
             info!("Discord client created successfully");
             client
@@ -59,6 +72,8 @@ async fn main() -> Result<()> {
     {
         let mut data = client.data.write().await;
         data.insert::<ShardManagerContainer>(client.shard_manager.clone());
+        #[cfg(feature = "songbird")]
+        data.insert::<voice::VoiceManagerContainer>(songbird_manager);
     }
 
     info!("Axis bot is starting up...");
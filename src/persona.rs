@@ -0,0 +1,67 @@
+//! Sends AI replies through a per-channel webhook so they can appear under a
+//! configurable display name and avatar instead of the bot's own account.
+use dashmap::DashMap;
+use serenity::builder::{CreateWebhook, ExecuteWebhook};
+use serenity::http::Http;
+use serenity::model::channel::Webhook;
+use serenity::model::id::ChannelId;
+use tracing::info;
+
+const WEBHOOK_NAME: &str = "Axis Persona";
+
+pub struct PersonaManager {
+    webhooks: DashMap<ChannelId, Webhook>,
+}
+
+impl PersonaManager {
+    pub fn new() -> Self {
+        Self { webhooks: DashMap::new() }
+    }
+
+    async fn get_or_create_webhook(&self, http: &Http, channel_id: ChannelId) -> Result<Webhook, serenity::Error> {
+        if let Some(webhook) = self.webhooks.get(&channel_id) {
+            return Ok(webhook.clone());
+        }
+
+        let existing = channel_id.webhooks(http).await?;
+        let webhook = match existing.into_iter().find(|w| w.name.as_deref() == Some(WEBHOOK_NAME)) {
+            Some(webhook) => webhook,
+            None => {
+                info!("Creating persona webhook in channel {}", channel_id);
+                channel_id.create_webhook(http, CreateWebhook::new(WEBHOOK_NAME)).await?
+            }
+        };
+
+        self.webhooks.insert(channel_id, webhook.clone());
+        Ok(webhook)
+    }
+
+    /// Sends `content` through this channel's persona webhook under
+    /// `username`/`avatar_url`. Returns `Err` if the bot lacks
+    /// `MANAGE_WEBHOOKS` (or the send otherwise fails) so the caller can fall
+    /// back to a plain reply.
+    pub async fn send_as(
+        &self,
+        http: &Http,
+        channel_id: ChannelId,
+        username: &str,
+        avatar_url: Option<&str>,
+        content: &str,
+    ) -> Result<(), serenity::Error> {
+        let webhook = self.get_or_create_webhook(http, channel_id).await?;
+
+        let mut builder = ExecuteWebhook::new().content(content).username(username);
+        if let Some(avatar_url) = avatar_url {
+            builder = builder.avatar_url(avatar_url);
+        }
+
+        if let Err(e) = webhook.execute(http, false, builder).await {
+            // The cached webhook may have been deleted out from under us (e.g.
+            // a moderator cleaning up integrations); drop it so the next send
+            // refetches or recreates one instead of failing forever.
+            self.webhooks.remove(&channel_id);
+            return Err(e);
+        }
+        Ok(())
+    }
+}
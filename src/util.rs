@@ -0,0 +1,118 @@
+//! Small formatting/sending helpers shared across the bot.
+use serenity::http::Http;
+use serenity::model::channel::Message;
+use serenity::model::id::ChannelId;
+
+/// Discord's hard cap on a single message's character count.
+pub const DISCORD_MESSAGE_LIMIT: usize = 2000;
+
+/// Splits `content` into chunks that each fit within `limit` characters,
+/// preferring to break on a blank line, then any newline, and only falling
+/// back to a hard cut if neither is available. Tracks fenced-code-block
+/// state across the whole string so a chunk never ends mid-fence: an open
+/// ` ``` ` is closed at the end of its chunk and reopened (with the same
+/// language tag) at the start of the next one.
+pub fn split_into_chunks(content: &str, limit: usize) -> Vec<String> {
+    if content.len() <= limit {
+        return vec![content.to_string()];
+    }
+
+    let mut chunks = Vec::new();
+    let mut remaining = content;
+    let mut open_fence_lang: Option<String> = None;
+
+    while !remaining.is_empty() {
+        if remaining.len() <= limit && with_opening_fence(&open_fence_lang, remaining).len() <= limit {
+            chunks.push(with_opening_fence(&open_fence_lang, remaining));
+            break;
+        }
+
+        // Leave headroom for a reopened AND/OR closed ``` fence, since a
+        // fence can open partway through this chunk (not just be carried in
+        // from the last one) and still need closing before the split point.
+        let reserved = 64;
+        let mut budget = floor_char_boundary(remaining, limit.saturating_sub(reserved));
+
+        let (chunk, rest, lang_at_end, piece) = loop {
+            let split_at = find_split_point(remaining, budget);
+            let (chunk, rest) = remaining.split_at(split_at);
+            let lang_at_end = fence_state_after(&open_fence_lang, chunk);
+
+            let mut piece = with_opening_fence(&open_fence_lang, chunk);
+            if lang_at_end.is_some() {
+                piece.push_str("\n```");
+            }
+
+            if piece.len() <= limit || split_at == 0 {
+                break (chunk, rest, lang_at_end, piece);
+            }
+            // The fence overhead still didn't fit in the reserved margin — back
+            // off and try a smaller budget rather than ship an over-limit chunk.
+            budget = floor_char_boundary(remaining, split_at.saturating_sub(piece.len() - limit).max(1));
+        };
+
+        chunks.push(piece);
+        open_fence_lang = lang_at_end;
+        remaining = rest.trim_start_matches('\n');
+    }
+
+    chunks
+}
+
+/// Re-prepends an opening fence for `lang` if one was left open by the
+/// previous chunk.
+fn with_opening_fence(lang: &Option<String>, text: &str) -> String {
+    match lang {
+        Some(lang) => format!("```{}\n{}", lang, text),
+        None => text.to_string(),
+    }
+}
+
+/// Scans `chunk` for ``` fence toggles, starting from `lang` (the state
+/// carried in from the previous chunk), and returns the language tag still
+/// open at the end, if any.
+fn fence_state_after(lang: &Option<String>, chunk: &str) -> Option<String> {
+    let mut state = lang.clone();
+    for line in chunk.lines() {
+        if let Some(tag) = line.trim_start().strip_prefix("```") {
+            state = match state {
+                Some(_) => None,
+                None => Some(tag.trim().to_string()),
+            };
+        }
+    }
+    state
+}
+
+/// Finds the best index (byte offset, <= `budget`) to split `text` at:
+/// a blank-line boundary first, then any newline, then a hard cut.
+fn find_split_point(text: &str, budget: usize) -> usize {
+    let window = &text[..budget.min(text.len())];
+
+    if let Some(pos) = window.rfind("\n\n") {
+        return pos + 2;
+    }
+    if let Some(pos) = window.rfind('\n') {
+        return pos + 1;
+    }
+    floor_char_boundary(text, budget)
+}
+
+/// Rounds `idx` down to the nearest UTF-8 char boundary so slicing never panics.
+fn floor_char_boundary(text: &str, idx: usize) -> usize {
+    let mut idx = idx.min(text.len());
+    while idx > 0 && !text.is_char_boundary(idx) {
+        idx -= 1;
+    }
+    idx
+}
+
+/// Sends `content` to `channel_id`, splitting across multiple messages if it
+/// exceeds Discord's message length limit. Returns every message sent, in order.
+pub async fn send_chunked(http: &Http, channel_id: ChannelId, content: &str) -> serenity::Result<Vec<Message>> {
+    let mut sent = Vec::with_capacity(1);
+    for chunk in split_into_chunks(content, DISCORD_MESSAGE_LIMIT) {
+        sent.push(channel_id.say(http, chunk).await?);
+    }
+    Ok(sent)
+}
@@ -0,0 +1,62 @@
+//! Voice channel plumbing backed by `songbird`. Gated behind the optional
+//! `songbird` Cargo feature so text-only deployments don't pull in the
+//! voice/codec dependency tree.
+#![cfg(feature = "songbird")]
+
+use anyhow::{anyhow, Result};
+use serenity::client::Context;
+use serenity::model::id::{ChannelId, GuildId, UserId};
+use std::sync::Arc;
+use tracing::info;
+
+/// Mirrors [`crate::bot::ShardManagerContainer`] so the songbird manager can
+/// be fetched out of `ctx.data` the same way the shard manager is.
+pub struct VoiceManagerContainer;
+
+impl serenity::prelude::TypeMapKey for VoiceManagerContainer {
+    type Value = Arc<songbird::Songbird>;
+}
+
+async fn manager(ctx: &Context) -> Result<Arc<songbird::Songbird>> {
+    let data = ctx.data.read().await;
+    data.get::<VoiceManagerContainer>()
+        .cloned()
+        .ok_or_else(|| anyhow!("Songbird voice manager was not inserted into client data"))
+}
+
+/// Looks up the voice channel a guild member is currently connected to.
+pub fn resolve_author_channel(ctx: &Context, guild_id: GuildId, user_id: UserId) -> Option<ChannelId> {
+    ctx.cache
+        .guild(guild_id)?
+        .voice_states
+        .get(&user_id)?
+        .channel_id
+}
+
+pub async fn join(ctx: &Context, guild_id: GuildId, channel_id: ChannelId) -> Result<()> {
+    let manager = manager(ctx).await?;
+    let (_handler, result) = manager.join(guild_id, channel_id).await;
+    result?;
+    info!("Joined voice channel {} in guild {}", channel_id, guild_id);
+    Ok(())
+}
+
+pub async fn leave(ctx: &Context, guild_id: GuildId) -> Result<()> {
+    let manager = manager(ctx).await?;
+    manager.remove(guild_id).await?;
+    info!("Left voice channel in guild {}", guild_id);
+    Ok(())
+}
+
+pub async fn play_url(ctx: &Context, guild_id: GuildId, url: &str) -> Result<()> {
+    let manager = manager(ctx).await?;
+    let call = manager
+        .get(guild_id)
+        .ok_or_else(|| anyhow!("Not connected to a voice channel in this server"))?;
+
+    let source = songbird::input::Input::from(songbird::ytdl(url).await?);
+    let mut handler = call.lock().await;
+    handler.play_input(source);
+    info!("Queued playback of {} in guild {}", url, guild_id);
+    Ok(())
+}